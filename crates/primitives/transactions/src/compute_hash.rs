@@ -3,15 +3,18 @@ use alloc::vec::Vec;
 use mp_felt::Felt252Wrapper;
 use mp_hashers::HasherT;
 use starknet_api::block;
+use starknet_api::data_availability::DataAvailabilityMode;
+use starknet_api::transaction::ResourceBoundsMapping;
 use starknet_core::{crypto::compute_hash_on_elements, utils::starknet_keccak};
-use starknet_crypto::FieldElement;
+use starknet_crypto::{pedersen_hash, poseidon_hash_many, FieldElement};
 
 use crate::{DeployTransaction, LEGACY_BLOCK_NUMBER, LEGACY_L1_HANDLER_BLOCK};
 
 use super::{
-    DeclareTransaction, DeclareTransactionV0, DeclareTransactionV1, DeclareTransactionV2, DeployAccountTransaction,
-    HandleL1MessageTransaction, InvokeTransaction, InvokeTransactionV0, InvokeTransactionV1, Transaction,
-    UserTransaction, SIMULATE_TX_VERSION_OFFSET,
+    DeclareTransaction, DeclareTransactionV0, DeclareTransactionV1, DeclareTransactionV2, DeclareTransactionV3,
+    DeployAccountTransaction, DeployAccountTransactionV1, DeployAccountTransactionV3, HandleL1MessageTransaction,
+    InvokeTransaction, InvokeTransactionV0, InvokeTransactionV1, InvokeTransactionV3, Transaction, UserTransaction,
+    SIMULATE_TX_VERSION_OFFSET,
 };
 
 const DECLARE_PREFIX: &[u8] = b"declare";
@@ -20,10 +23,138 @@ const DEPLOY_PREFIX: &[u8] = b"deploy";
 const INVOKE_PREFIX: &[u8] = b"invoke";
 const L1_HANDLER_PREFIX: &[u8] = b"l1_handler";
 
+// Cairo string for "L1_GAS" / "L2_GAS", used to tag each resource bound before it is packed into
+// a single felt.
+const L1_GAS_NAME: &[u8] = b"L1_GAS";
+const L2_GAS_NAME: &[u8] = b"L2_GAS";
+
 pub trait ComputeTransactionHash {
     fn compute_hash<H: HasherT>(&self, chain_id: Felt252Wrapper, is_query: bool, block_number: Option<u64>) -> Felt252Wrapper;
 }
 
+/// Packs a resource bound into a single felt as `(resource_name << 192) | (max_amount << 128) |
+/// max_price_per_unit`, following the v3 fee hash layout.
+fn resource_bound_to_felt(resource_name: &[u8], max_amount: u64, max_price_per_unit: u128) -> FieldElement {
+    let shift_64 = FieldElement::from(1u128 << 64);
+    let shift_128 = shift_64 * shift_64;
+    let shift_192 = shift_128 * shift_64;
+
+    FieldElement::from_byte_slice_be(resource_name).unwrap() * shift_192
+        + FieldElement::from(max_amount) * shift_128
+        + FieldElement::from(max_price_per_unit)
+}
+
+/// `poseidon_hash_many(&[tip, packed_l1_gas, packed_l2_gas])`, shared by all v3 transactions.
+fn compute_fee_hash(tip: u64, resource_bounds: &ResourceBoundsMapping) -> FieldElement {
+    poseidon_hash_many(&[
+        FieldElement::from(tip),
+        resource_bound_to_felt(L1_GAS_NAME, resource_bounds.l1_gas.max_amount, resource_bounds.l1_gas.max_price_per_unit),
+        resource_bound_to_felt(L2_GAS_NAME, resource_bounds.l2_gas.max_amount, resource_bounds.l2_gas.max_price_per_unit),
+    ])
+}
+
+/// `(nonce_da_mode << 32) | fee_da_mode`, each mode being `0` (L1) or `1` (L2).
+fn da_mode_to_felt(nonce_da_mode: DataAvailabilityMode, fee_da_mode: DataAvailabilityMode) -> FieldElement {
+    let nonce_da_mode = da_mode_as_u64(nonce_da_mode);
+    let fee_da_mode = da_mode_as_u64(fee_da_mode);
+
+    FieldElement::from(nonce_da_mode * (1u64 << 32) + fee_da_mode)
+}
+
+fn da_mode_as_u64(da_mode: DataAvailabilityMode) -> u64 {
+    match da_mode {
+        DataAvailabilityMode::L1 => 0,
+        DataAvailabilityMode::L2 => 1,
+    }
+}
+
+/// The hash preimage layout a pre-v3 transaction type used at a given point in the chain's
+/// history. Networks migrated through these in lockstep, but not at the same block height, hence
+/// [`ForkSchedule`] resolving a `SpecVersion` from a `(chain_id, block_number)` pair rather than
+/// every call site comparing against a shared magic number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpecVersion {
+    /// Earliest layout: no `version` field, no fee, L1-handler transactions still hashed with the
+    /// `invoke` prefix.
+    Genesis,
+    /// L1-handler transactions moved to the dedicated `l1_handler` prefix, but invoke/deploy still
+    /// omit `version` and fee from the preimage.
+    Legacy,
+    /// Current layout: `version` and fee are part of every preimage.
+    Current,
+}
+
+/// Maps block-number ranges to the [`SpecVersion`] active at that height, per `chain_id`. Fork
+/// heights differ per network, so this is constructed from the `chain_id` rather than hardcoded.
+/// `legacy_block` is only meaningful when `has_legacy_history` is set — chains that never ran the
+/// legacy format have no fork height to speak of, so block `0` can't double as that sentinel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ForkSchedule {
+    l1_handler_block: u64,
+    legacy_block: u64,
+    has_legacy_history: bool,
+}
+
+// Cairo string for "SN_MAIN"
+const MAINNET_CHAIN_ID: &[u8] = b"SN_MAIN";
+
+impl ForkSchedule {
+    pub fn new(l1_handler_block: u64, legacy_block: u64) -> Self {
+        Self { l1_handler_block, legacy_block, has_legacy_history: true }
+    }
+
+    /// A schedule for chains that never ran the legacy pre-fee-field format: every block height,
+    /// and no block context at all, resolves to [`SpecVersion::Current`].
+    pub fn without_legacy_history() -> Self {
+        Self { l1_handler_block: 0, legacy_block: 0, has_legacy_history: false }
+    }
+
+    /// The fork schedule for `chain_id`. Only mainnet carries the legacy pre-fee-field history;
+    /// every other chain (testnets, and any custom chain operators stand up) never ran the legacy
+    /// format, so every block height on them resolves to [`SpecVersion::Current`]. The
+    /// `compute_hash` implementations always obtain their schedule from this constructor; a
+    /// schedule built with [`ForkSchedule::new`] is not used by them.
+    pub fn for_chain_id(chain_id: Felt252Wrapper) -> Self {
+        let chain_id: FieldElement = chain_id.into();
+        if chain_id == FieldElement::from_byte_slice_be(MAINNET_CHAIN_ID).unwrap() {
+            Self::new(LEGACY_L1_HANDLER_BLOCK, LEGACY_BLOCK_NUMBER)
+        } else {
+            Self::without_legacy_history()
+        }
+    }
+
+    /// Resolves the [`SpecVersion`] active at `block_number` for invoke-v0 / deploy transactions.
+    /// Mirrors the historical `block_number > Some(LEGACY_BLOCK_NUMBER)` check: strictly greater
+    /// than the fork height is [`SpecVersion::Current`], everything else — including no block
+    /// context at all — is [`SpecVersion::Legacy`]. Chains with no legacy history at all always
+    /// resolve to [`SpecVersion::Current`], regardless of `block_number`.
+    pub fn at_invoke_or_deploy(&self, block_number: Option<u64>) -> SpecVersion {
+        if !self.has_legacy_history {
+            return SpecVersion::Current;
+        }
+        match block_number {
+            Some(block_number) if block_number > self.legacy_block => SpecVersion::Current,
+            _ => SpecVersion::Legacy,
+        }
+    }
+
+    /// Resolves the [`SpecVersion`] active at `block_number` for L1-handler transactions. Mirrors
+    /// the historical `block_number < Some(LEGACY_L1_HANDLER_BLOCK)` / `< Some(LEGACY_BLOCK_NUMBER)`
+    /// checks: both are strict, so a height exactly at a fork boundary — and no block context at
+    /// all — resolves to the *later* spec, unlike [`Self::at_invoke_or_deploy`]. Chains with no
+    /// legacy history at all always resolve to [`SpecVersion::Current`].
+    pub fn at_l1_handler(&self, block_number: Option<u64>) -> SpecVersion {
+        if !self.has_legacy_history {
+            return SpecVersion::Current;
+        }
+        match block_number {
+            Some(block_number) if block_number < self.l1_handler_block => SpecVersion::Genesis,
+            Some(block_number) if block_number < self.legacy_block => SpecVersion::Legacy,
+            _ => SpecVersion::Current,
+        }
+    }
+}
+
 fn convert_calldata(data: &[Felt252Wrapper]) -> &[FieldElement] {
     // Non-copy but less dangerous than transmute
     // https://doc.rust-lang.org/std/mem/fn.transmute.html#alternatives
@@ -38,11 +169,11 @@ impl ComputeTransactionHash for InvokeTransactionV0 {
         let entrypoint_selector = self.entry_point_selector.into();
         let calldata_hash = compute_hash_on_elements(convert_calldata(&self.calldata));
         let max_fee = FieldElement::from(self.max_fee);
+        let spec = ForkSchedule::for_chain_id(chain_id).at_invoke_or_deploy(block_number);
         let chain_id = chain_id.into();
 
-        // Check for deprecated environment
-        if block_number >  Some(LEGACY_BLOCK_NUMBER) {
-            H::compute_hash_on_elements(&[
+        match spec {
+            SpecVersion::Current => H::compute_hash_on_elements(&[
                 prefix,
                 version,
                 contract_address,
@@ -51,16 +182,15 @@ impl ComputeTransactionHash for InvokeTransactionV0 {
                 max_fee,
                 chain_id,
             ])
-            .into()
-        } else {
-            H::compute_hash_on_elements(&[
+            .into(),
+            SpecVersion::Legacy | SpecVersion::Genesis => H::compute_hash_on_elements(&[
                 prefix,
                 contract_address,
                 entrypoint_selector,
                 calldata_hash,
                 chain_id,
             ])
-            .into()
+            .into(),
         }
     }
 }
@@ -90,11 +220,70 @@ impl ComputeTransactionHash for InvokeTransactionV1 {
     }
 }
 
+/// `poseidon_hash_many` over the element order an invoke-v3 preimage uses. Pulled out of
+/// [`InvokeTransactionV3::compute_hash`] so the ordering itself — the thing a transposed
+/// `paymaster_hash`/`account_deployment_data_hash` would silently break — can be pinned by a test
+/// independently of constructing a full transaction.
+#[allow(clippy::too_many_arguments)]
+fn invoke_v3_preimage(
+    version: FieldElement,
+    sender_address: FieldElement,
+    fee_hash: FieldElement,
+    paymaster_hash: FieldElement,
+    chain_id: FieldElement,
+    nonce: FieldElement,
+    da_modes: FieldElement,
+    account_deployment_data_hash: FieldElement,
+    calldata_hash: FieldElement,
+) -> FieldElement {
+    poseidon_hash_many(&[
+        FieldElement::from_byte_slice_be(INVOKE_PREFIX).unwrap(),
+        version,
+        sender_address,
+        fee_hash,
+        paymaster_hash,
+        chain_id,
+        nonce,
+        da_modes,
+        account_deployment_data_hash,
+        calldata_hash,
+    ])
+}
+
+impl ComputeTransactionHash for InvokeTransactionV3 {
+    fn compute_hash<H: HasherT>(&self, chain_id: Felt252Wrapper, is_query: bool, _block_number: Option<u64>) -> Felt252Wrapper {
+        let version = if is_query {
+            SIMULATE_TX_VERSION_OFFSET + FieldElement::from(3u8)
+        } else {
+            FieldElement::from(3u8)
+        };
+        let fee_hash = compute_fee_hash(self.tip, &self.resource_bounds);
+        let paymaster_hash = poseidon_hash_many(convert_calldata(&self.paymaster_data));
+        let da_modes = da_mode_to_felt(self.nonce_data_availability_mode, self.fee_data_availability_mode);
+        let account_deployment_data_hash = poseidon_hash_many(convert_calldata(&self.account_deployment_data));
+        let calldata_hash = poseidon_hash_many(convert_calldata(&self.calldata));
+
+        invoke_v3_preimage(
+            version,
+            self.sender_address.into(),
+            fee_hash,
+            paymaster_hash,
+            chain_id.into(),
+            FieldElement::from(self.nonce),
+            da_modes,
+            account_deployment_data_hash,
+            calldata_hash,
+        )
+        .into()
+    }
+}
+
 impl ComputeTransactionHash for InvokeTransaction {
     fn compute_hash<H: HasherT>(&self, chain_id: Felt252Wrapper, is_query: bool, block_number: Option<u64>) -> Felt252Wrapper {
         match self {
             InvokeTransaction::V0(tx) => tx.compute_hash::<H>(chain_id, is_query, block_number),
             InvokeTransaction::V1(tx) => tx.compute_hash::<H>(chain_id, is_query, block_number),
+            InvokeTransaction::V3(tx) => tx.compute_hash::<H>(chain_id, is_query, block_number),
         }
     }
 }
@@ -176,18 +365,86 @@ impl ComputeTransactionHash for DeclareTransactionV2 {
     }
 }
 
+/// `poseidon_hash_many` over the element order a declare-v3 preimage uses. Pulled out of
+/// [`DeclareTransactionV3::compute_hash`] for the same reason as [`invoke_v3_preimage`].
+#[allow(clippy::too_many_arguments)]
+fn declare_v3_preimage(
+    version: FieldElement,
+    sender_address: FieldElement,
+    fee_hash: FieldElement,
+    paymaster_hash: FieldElement,
+    chain_id: FieldElement,
+    nonce: FieldElement,
+    da_modes: FieldElement,
+    account_deployment_data_hash: FieldElement,
+    class_hash: FieldElement,
+    compiled_class_hash: FieldElement,
+) -> FieldElement {
+    poseidon_hash_many(&[
+        FieldElement::from_byte_slice_be(DECLARE_PREFIX).unwrap(),
+        version,
+        sender_address,
+        fee_hash,
+        paymaster_hash,
+        chain_id,
+        nonce,
+        da_modes,
+        account_deployment_data_hash,
+        class_hash,
+        compiled_class_hash,
+    ])
+}
+
+impl ComputeTransactionHash for DeclareTransactionV3 {
+    fn compute_hash<H: HasherT>(&self, chain_id: Felt252Wrapper, is_query: bool, _block_number: Option<u64>) -> Felt252Wrapper {
+        let version = if is_query {
+            SIMULATE_TX_VERSION_OFFSET + FieldElement::from(3u8)
+        } else {
+            FieldElement::from(3u8)
+        };
+        let fee_hash = compute_fee_hash(self.tip, &self.resource_bounds);
+        let paymaster_hash = poseidon_hash_many(convert_calldata(&self.paymaster_data));
+        let da_modes = da_mode_to_felt(self.nonce_data_availability_mode, self.fee_data_availability_mode);
+        let account_deployment_data_hash = poseidon_hash_many(convert_calldata(&self.account_deployment_data));
+
+        declare_v3_preimage(
+            version,
+            self.sender_address.into(),
+            fee_hash,
+            paymaster_hash,
+            chain_id.into(),
+            FieldElement::from(self.nonce),
+            da_modes,
+            account_deployment_data_hash,
+            self.class_hash.into(),
+            self.compiled_class_hash.into(),
+        )
+        .into()
+    }
+}
+
 impl ComputeTransactionHash for DeclareTransaction {
     fn compute_hash<H: HasherT>(&self, chain_id: Felt252Wrapper, is_query: bool, block_number: Option<u64>) -> Felt252Wrapper {
         match self {
             DeclareTransaction::V0(tx) => tx.compute_hash::<H>(chain_id, is_query, None),
             DeclareTransaction::V1(tx) => tx.compute_hash::<H>(chain_id, is_query, None),
             DeclareTransaction::V2(tx) => tx.compute_hash::<H>(chain_id, is_query, None),
+            DeclareTransaction::V3(tx) => tx.compute_hash::<H>(chain_id, is_query, None),
         }
     }
 }
 
 impl ComputeTransactionHash for DeployAccountTransaction {
     fn compute_hash<H: HasherT>(&self, chain_id: Felt252Wrapper, is_query: bool, block_number: Option<u64>) -> Felt252Wrapper {
+        match self {
+            DeployAccountTransaction::V1(tx) => tx.compute_hash::<H>(chain_id, is_query, block_number),
+            DeployAccountTransaction::V3(tx) => tx.compute_hash::<H>(chain_id, is_query, block_number),
+        }
+    }
+}
+
+impl ComputeTransactionHash for DeployAccountTransactionV1 {
+    fn compute_hash<H: HasherT>(&self, chain_id: Felt252Wrapper, is_query: bool, _block_number: Option<u64>) -> Felt252Wrapper {
         let chain_id = chain_id.into();
         let contract_address = self.get_account_address();
 
@@ -195,47 +452,144 @@ impl ComputeTransactionHash for DeployAccountTransaction {
     }
 }
 
+/// `poseidon_hash_many` over the element order a deploy-account-v3 preimage uses. Pulled out of
+/// [`DeployAccountTransactionV3::compute_hash`] for the same reason as [`invoke_v3_preimage`].
+#[allow(clippy::too_many_arguments)]
+fn deploy_account_v3_preimage(
+    version: FieldElement,
+    contract_address: FieldElement,
+    fee_hash: FieldElement,
+    paymaster_hash: FieldElement,
+    chain_id: FieldElement,
+    nonce: FieldElement,
+    da_modes: FieldElement,
+    constructor_calldata_hash: FieldElement,
+    class_hash: FieldElement,
+    contract_address_salt: FieldElement,
+) -> FieldElement {
+    poseidon_hash_many(&[
+        FieldElement::from_byte_slice_be(DEPLOY_ACCOUNT_PREFIX).unwrap(),
+        version,
+        contract_address,
+        fee_hash,
+        paymaster_hash,
+        chain_id,
+        nonce,
+        da_modes,
+        constructor_calldata_hash,
+        class_hash,
+        contract_address_salt,
+    ])
+}
+
+impl ComputeTransactionHash for DeployAccountTransactionV3 {
+    fn compute_hash<H: HasherT>(&self, chain_id: Felt252Wrapper, is_query: bool, _block_number: Option<u64>) -> Felt252Wrapper {
+        let chain_id_felt = chain_id.into();
+        let contract_address = self.get_account_address();
+
+        let version = if is_query {
+            SIMULATE_TX_VERSION_OFFSET + FieldElement::from(3u8)
+        } else {
+            FieldElement::from(3u8)
+        };
+        let fee_hash = compute_fee_hash(self.tip, &self.resource_bounds);
+        let paymaster_hash = poseidon_hash_many(convert_calldata(&self.paymaster_data));
+        let da_modes = da_mode_to_felt(self.nonce_data_availability_mode, self.fee_data_availability_mode);
+        let constructor_calldata_hash = poseidon_hash_many(convert_calldata(&self.constructor_calldata));
+
+        deploy_account_v3_preimage(
+            version,
+            contract_address,
+            fee_hash,
+            paymaster_hash,
+            chain_id_felt,
+            FieldElement::from(self.nonce),
+            da_modes,
+            constructor_calldata_hash,
+            self.class_hash.into(),
+            self.contract_address_salt.into(),
+        )
+        .into()
+    }
+}
+
 impl ComputeTransactionHash for DeployTransaction {
     fn compute_hash<H: HasherT>(&self, chain_id: Felt252Wrapper, is_query: bool, block_number: Option<u64>) -> Felt252Wrapper {
+        let spec = ForkSchedule::for_chain_id(chain_id).at_invoke_or_deploy(block_number);
         let chain_id = chain_id.into();
         let contract_address = self.get_account_address();
 
-        self.compute_hash_given_contract_address::<H>(chain_id, contract_address, is_query, block_number).into()
+        self.compute_hash_given_contract_address::<H>(chain_id, contract_address, is_query, spec).into()
     }
 }
 
-impl DeployAccountTransaction {
+/// Cairo string for "STARKNET_CONTRACT_ADDRESS"
+const PREFIX_CONTRACT_ADDRESS: FieldElement = FieldElement::from_mont([
+    3829237882463328880,
+    17289941567720117366,
+    8635008616843941496,
+    533439743893157637,
+]);
+// 2 ** 251 - 256
+const ADDR_BOUND: FieldElement =
+    FieldElement::from_mont([18446743986131443745, 160989183, 18446744073709255680, 576459263475590224]);
+
+/// Computes a contract address as `pedersen(["STARKNET_CONTRACT_ADDRESS", deployer_address, salt,
+/// class_hash, pedersen(constructor_calldata)]) % ADDR_BOUND`. Direct `deploy`/`deploy_account`
+/// transactions pass a zero `deployer_address`; [`compute_udc_contract_address`] passes the
+/// deployer when `unique` is set.
+pub fn compute_contract_address(
+    salt: FieldElement,
+    class_hash: FieldElement,
+    constructor_calldata: &[FieldElement],
+    deployer_address: FieldElement,
+) -> FieldElement {
+    starknet_core::crypto::compute_hash_on_elements(&[
+        PREFIX_CONTRACT_ADDRESS,
+        deployer_address,
+        salt,
+        class_hash,
+        starknet_core::crypto::compute_hash_on_elements(constructor_calldata),
+    ]) % ADDR_BOUND
+}
+
+/// Computes the address a contract deployed through the Universal Deployer Contract (UDC) will
+/// get. When `unique` is set, the salt is rehashed as `pedersen(deployer_address, salt)` and the
+/// deployer address participates in the derivation instead of the zero element, matching the
+/// UDC's `deployContract`/`deployContractUniqueSalt` entrypoints.
+pub fn compute_udc_contract_address(
+    salt: FieldElement,
+    class_hash: FieldElement,
+    constructor_calldata: &[FieldElement],
+    deployer_address: FieldElement,
+    unique: bool,
+) -> FieldElement {
+    if unique {
+        let salt = pedersen_hash(&deployer_address, &salt);
+        compute_contract_address(salt, class_hash, constructor_calldata, deployer_address)
+    } else {
+        compute_contract_address(salt, class_hash, constructor_calldata, FieldElement::ZERO)
+    }
+}
+
+impl DeployAccountTransactionV1 {
     pub fn get_account_address(&self) -> FieldElement {
-        Self::calculate_contract_address(
+        compute_contract_address(
             self.contract_address_salt.into(),
             self.class_hash.into(),
             convert_calldata(&self.constructor_calldata),
+            FieldElement::ZERO,
         )
     }
 
+    /// Pre-unification alias for [`compute_contract_address`] with a zero deployer.
+    #[deprecated(note = "use compute_contract_address instead")]
     pub fn calculate_contract_address(
         contract_address_salt: FieldElement,
         class_hash: FieldElement,
         constructor_calldata: &[FieldElement],
     ) -> FieldElement {
-        /// Cairo string for "STARKNET_CONTRACT_ADDRESS"
-        const PREFIX_CONTRACT_ADDRESS: FieldElement = FieldElement::from_mont([
-            3829237882463328880,
-            17289941567720117366,
-            8635008616843941496,
-            533439743893157637,
-        ]);
-        // 2 ** 251 - 256
-        const ADDR_BOUND: FieldElement =
-            FieldElement::from_mont([18446743986131443745, 160989183, 18446744073709255680, 576459263475590224]);
-
-        starknet_core::crypto::compute_hash_on_elements(&[
-            PREFIX_CONTRACT_ADDRESS,
-            FieldElement::ZERO,
-            contract_address_salt,
-            class_hash,
-            starknet_core::crypto::compute_hash_on_elements(constructor_calldata),
-        ]) % ADDR_BOUND
+        compute_contract_address(contract_address_salt, class_hash, constructor_calldata, FieldElement::ZERO)
     }
 
     pub(super) fn compute_hash_given_contract_address<H: HasherT>(
@@ -261,38 +615,35 @@ impl DeployAccountTransaction {
     }
 }
 
+impl DeployAccountTransactionV3 {
+    pub fn get_account_address(&self) -> FieldElement {
+        compute_contract_address(
+            self.contract_address_salt.into(),
+            self.class_hash.into(),
+            convert_calldata(&self.constructor_calldata),
+            FieldElement::ZERO,
+        )
+    }
+}
+
 impl DeployTransaction {
     pub fn get_account_address(&self) -> FieldElement {
-        Self::calculate_contract_address(
+        compute_contract_address(
             self.contract_address_salt.into(),
             self.class_hash.into(),
             convert_calldata(&self.constructor_calldata),
+            FieldElement::ZERO,
         )
     }
 
+    /// Pre-unification alias for [`compute_contract_address`] with a zero deployer.
+    #[deprecated(note = "use compute_contract_address instead")]
     pub fn calculate_contract_address(
         contract_address_salt: FieldElement,
         class_hash: FieldElement,
         constructor_calldata: &[FieldElement],
     ) -> FieldElement {
-        /// Cairo string for "STARKNET_CONTRACT_ADDRESS"
-        const PREFIX_CONTRACT_ADDRESS: FieldElement = FieldElement::from_mont([
-            3829237882463328880,
-            17289941567720117366,
-            8635008616843941496,
-            533439743893157637,
-        ]);
-        // 2 ** 251 - 256
-        const ADDR_BOUND: FieldElement =
-            FieldElement::from_mont([18446743986131443745, 160989183, 18446744073709255680, 576459263475590224]);
-
-        starknet_core::crypto::compute_hash_on_elements(&[
-            PREFIX_CONTRACT_ADDRESS,
-            FieldElement::ZERO,
-            contract_address_salt,
-            class_hash,
-            starknet_core::crypto::compute_hash_on_elements(constructor_calldata),
-        ]) % ADDR_BOUND
+        compute_contract_address(contract_address_salt, class_hash, constructor_calldata, FieldElement::ZERO)
     }
 
     pub(super) fn compute_hash_given_contract_address<H: HasherT>(
@@ -300,33 +651,32 @@ impl DeployTransaction {
         chain_id: FieldElement,
         contract_address: FieldElement,
         is_query: bool,
-        block_number: Option<u64>
+        spec: SpecVersion,
     ) -> FieldElement {
         let prefix = FieldElement::from_byte_slice_be(DEPLOY_PREFIX).unwrap();
         let version = FieldElement::ZERO;
         let constructor_calldata = compute_hash_on_elements(convert_calldata(&self.constructor_calldata));
         let constructor = starknet_keccak(b"constructor");
 
-        if block_number >  Some(LEGACY_BLOCK_NUMBER) { 
-            H::compute_hash_on_elements(&[
+        match spec {
+            SpecVersion::Current => H::compute_hash_on_elements(&[
                 prefix,
                 version,
                 contract_address,
                 constructor,
                 constructor_calldata,
                 FieldElement::ZERO,
-                chain_id
+                chain_id,
             ])
-            .into()
-        } else {
-            H::compute_hash_on_elements(&[
+            .into(),
+            SpecVersion::Legacy | SpecVersion::Genesis => H::compute_hash_on_elements(&[
                 prefix,
                 contract_address,
                 constructor,
                 constructor_calldata,
-                chain_id
+                chain_id,
             ])
-            .into()
+            .into(),
         }
     }
 }
@@ -339,30 +689,29 @@ impl ComputeTransactionHash for HandleL1MessageTransaction {
         let contract_address = self.contract_address.into();
         let entrypoint_selector = self.entry_point_selector.into();
         let calldata_hash = compute_hash_on_elements(convert_calldata(&self.calldata));
+        let spec = ForkSchedule::for_chain_id(chain_id).at_l1_handler(block_number);
         let chain_id = chain_id.into();
         let nonce = self.nonce.into();
 
-        if block_number < Some(LEGACY_L1_HANDLER_BLOCK) && block_number != None {
-            H::compute_hash_on_elements(&[
+        match spec {
+            SpecVersion::Genesis => H::compute_hash_on_elements(&[
                 invoke_prefix,
                 contract_address,
                 entrypoint_selector,
                 calldata_hash,
                 chain_id,
             ])
-            .into()
-        } else if block_number < Some(LEGACY_BLOCK_NUMBER) && block_number != None {
-            H::compute_hash_on_elements(&[
+            .into(),
+            SpecVersion::Legacy => H::compute_hash_on_elements(&[
                 prefix,
                 contract_address,
                 entrypoint_selector,
                 calldata_hash,
                 chain_id,
-                nonce
+                nonce,
             ])
-            .into()
-        } else {
-            H::compute_hash_on_elements(&[
+            .into(),
+            SpecVersion::Current => H::compute_hash_on_elements(&[
                 prefix,
                 version,
                 contract_address,
@@ -372,7 +721,7 @@ impl ComputeTransactionHash for HandleL1MessageTransaction {
                 chain_id,
                 nonce,
             ])
-            .into()
+            .into(),
         }
     }
 }
@@ -0,0 +1,242 @@
+use starknet_api::transaction::ResourceBounds;
+
+use super::*;
+
+fn mainnet_chain_id() -> Felt252Wrapper {
+    FieldElement::from_byte_slice_be(MAINNET_CHAIN_ID).unwrap().into()
+}
+
+fn non_mainnet_chain_id() -> Felt252Wrapper {
+    FieldElement::from_byte_slice_be(b"SN_SEPOLIA").unwrap().into()
+}
+
+#[test]
+fn fork_schedule_invoke_or_deploy_matches_legacy_boundary_operators() {
+    let schedule = ForkSchedule::new(10, 100);
+
+    // Historical check was `block_number > Some(legacy_block)`, so equality stays Legacy.
+    assert_eq!(schedule.at_invoke_or_deploy(Some(99)), SpecVersion::Legacy);
+    assert_eq!(schedule.at_invoke_or_deploy(Some(100)), SpecVersion::Legacy);
+    assert_eq!(schedule.at_invoke_or_deploy(Some(101)), SpecVersion::Current);
+    // `None > Some(x)` is always false in Rust, so no block context stayed on the Legacy branch.
+    assert_eq!(schedule.at_invoke_or_deploy(None), SpecVersion::Legacy);
+}
+
+#[test]
+fn fork_schedule_l1_handler_matches_legacy_boundary_operators() {
+    let schedule = ForkSchedule::new(10, 100);
+
+    // Historical checks were strict `<`, so equality falls through to the next (later) spec.
+    assert_eq!(schedule.at_l1_handler(Some(9)), SpecVersion::Genesis);
+    assert_eq!(schedule.at_l1_handler(Some(10)), SpecVersion::Legacy);
+    assert_eq!(schedule.at_l1_handler(Some(99)), SpecVersion::Legacy);
+    assert_eq!(schedule.at_l1_handler(Some(100)), SpecVersion::Current);
+    assert_eq!(schedule.at_l1_handler(Some(101)), SpecVersion::Current);
+    // The historical guard excluded `None` from both comparisons, landing it on the final `else`.
+    assert_eq!(schedule.at_l1_handler(None), SpecVersion::Current);
+}
+
+#[test]
+fn fork_schedule_for_chain_id_only_applies_legacy_heights_on_mainnet() {
+    let mainnet = ForkSchedule::for_chain_id(mainnet_chain_id());
+    assert_eq!(mainnet.at_invoke_or_deploy(Some(LEGACY_BLOCK_NUMBER)), SpecVersion::Legacy);
+    assert_eq!(mainnet.at_invoke_or_deploy(Some(LEGACY_BLOCK_NUMBER + 1)), SpecVersion::Current);
+
+    let other = ForkSchedule::for_chain_id(non_mainnet_chain_id());
+    assert_eq!(other.at_invoke_or_deploy(Some(0)), SpecVersion::Current);
+    assert_eq!(other.at_invoke_or_deploy(None), SpecVersion::Current);
+    assert_eq!(other.at_l1_handler(Some(0)), SpecVersion::Current);
+    assert_eq!(other.at_l1_handler(None), SpecVersion::Current);
+}
+
+#[test]
+fn resource_bound_to_felt_packs_name_amount_and_price() {
+    // (resource_name << 192) | (max_amount << 128) | max_price_per_unit, checked against an
+    // independently computed vector.
+    assert_eq!(
+        resource_bound_to_felt(L1_GAS_NAME, 5, 7),
+        FieldElement::from_hex_be("0x4c315f474153000000000000000500000000000000000000000000000007").unwrap()
+    );
+    assert_eq!(
+        resource_bound_to_felt(L2_GAS_NAME, 11, 13),
+        FieldElement::from_hex_be("0x4c325f474153000000000000000b0000000000000000000000000000000d").unwrap()
+    );
+}
+
+#[test]
+fn compute_udc_contract_address_unique_differs_from_direct_and_matches_vector() {
+    let salt = FieldElement::from(42u64);
+    let class_hash = FieldElement::from(99u64);
+    let calldata = [FieldElement::from(1u64), FieldElement::from(2u64), FieldElement::from(3u64)];
+    let deployer = FieldElement::from(777u64);
+
+    let direct = compute_contract_address(salt, class_hash, &calldata, FieldElement::ZERO);
+    assert_eq!(
+        direct,
+        FieldElement::from_hex_be("0x6bf365ab02d1039b6877f90432e3f59446e02bc86a4482f414e821b5f5216c9").unwrap()
+    );
+
+    // unique=false behaves exactly like a direct deploy: zero deployer, untouched salt.
+    let udc_not_unique = compute_udc_contract_address(salt, class_hash, &calldata, deployer, false);
+    assert_eq!(udc_not_unique, direct);
+
+    // unique=true rehashes the salt with the deployer and folds the deployer into the address,
+    // so it must land on a different, independently-computed address.
+    let udc_unique = compute_udc_contract_address(salt, class_hash, &calldata, deployer, true);
+    assert_eq!(
+        udc_unique,
+        FieldElement::from_hex_be("0x3bcbb6858b54e07dc698751dfe3cd2586dfa98022508d3652ca509acbb3add").unwrap()
+    );
+    assert_ne!(udc_unique, direct);
+}
+
+#[test]
+fn compute_fee_hash_matches_independently_computed_vector() {
+    let resource_bounds = ResourceBoundsMapping {
+        l1_gas: ResourceBounds { max_amount: 5, max_price_per_unit: 7 },
+        l2_gas: ResourceBounds { max_amount: 11, max_price_per_unit: 13 },
+    };
+
+    assert_eq!(
+        compute_fee_hash(9, &resource_bounds),
+        FieldElement::from_hex_be("0x36e1ab272a2aaf2f9e196580ab4465c6e438fc92e70d2ce1d37a1788830bf30").unwrap()
+    );
+}
+
+// The per-helper tests in this file pin `resource_bound_to_felt`/`compute_fee_hash`/`da_mode_to_felt` in
+// isolation, but nothing previously exercised the element *order* each `V3::compute_hash` impl
+// feeds to `poseidon_hash_many` — a transposition (e.g. swapping `paymaster_hash` and
+// `account_deployment_data_hash`) would still leave every helper's own vector matching. The
+// preimage assembly for each v3 type is now its own pure function so that order can be pinned
+// directly: each `*_v3_preimage_matches_element_order` test below gives every argument a distinct value and checks the result against
+// `poseidon_hash_many` called with that same argument list spelled out independently, straight
+// from the layout in the request. A future transposition inside `{invoke,declare,deploy_account}_v3_preimage`
+// changes which element lands in which slot and so produces a different hash, failing the assertion.
+
+#[test]
+fn invoke_v3_preimage_matches_element_order() {
+    let version = FieldElement::from(3u8);
+    let sender_address = FieldElement::from(1u64);
+    let fee_hash = FieldElement::from(2u64);
+    let paymaster_hash = FieldElement::from(10u64);
+    let chain_id = FieldElement::from(4u64);
+    let nonce = FieldElement::from(5u64);
+    let da_modes = FieldElement::from(6u64);
+    let account_deployment_data_hash = FieldElement::from(7u64);
+    let calldata_hash = FieldElement::from(8u64);
+
+    let actual =
+        invoke_v3_preimage(version, sender_address, fee_hash, paymaster_hash, chain_id, nonce, da_modes, account_deployment_data_hash, calldata_hash);
+    let expected = poseidon_hash_many(&[
+        FieldElement::from_byte_slice_be(INVOKE_PREFIX).unwrap(),
+        version,
+        sender_address,
+        fee_hash,
+        paymaster_hash,
+        chain_id,
+        nonce,
+        da_modes,
+        account_deployment_data_hash,
+        calldata_hash,
+    ]);
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn declare_v3_preimage_matches_element_order() {
+    let version = FieldElement::from(3u8);
+    let sender_address = FieldElement::from(1u64);
+    let fee_hash = FieldElement::from(2u64);
+    let paymaster_hash = FieldElement::from(10u64);
+    let chain_id = FieldElement::from(4u64);
+    let nonce = FieldElement::from(5u64);
+    let da_modes = FieldElement::from(6u64);
+    let account_deployment_data_hash = FieldElement::from(7u64);
+    let class_hash = FieldElement::from(8u64);
+    let compiled_class_hash = FieldElement::from(9u64);
+
+    let actual = declare_v3_preimage(
+        version,
+        sender_address,
+        fee_hash,
+        paymaster_hash,
+        chain_id,
+        nonce,
+        da_modes,
+        account_deployment_data_hash,
+        class_hash,
+        compiled_class_hash,
+    );
+    let expected = poseidon_hash_many(&[
+        FieldElement::from_byte_slice_be(DECLARE_PREFIX).unwrap(),
+        version,
+        sender_address,
+        fee_hash,
+        paymaster_hash,
+        chain_id,
+        nonce,
+        da_modes,
+        account_deployment_data_hash,
+        class_hash,
+        compiled_class_hash,
+    ]);
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn deploy_account_v3_preimage_matches_element_order() {
+    let version = FieldElement::from(3u8);
+    let contract_address = FieldElement::from(1u64);
+    let fee_hash = FieldElement::from(2u64);
+    let paymaster_hash = FieldElement::from(10u64);
+    let chain_id = FieldElement::from(4u64);
+    let nonce = FieldElement::from(5u64);
+    let da_modes = FieldElement::from(6u64);
+    let constructor_calldata_hash = FieldElement::from(7u64);
+    let class_hash = FieldElement::from(8u64);
+    let contract_address_salt = FieldElement::from(9u64);
+
+    let actual = deploy_account_v3_preimage(
+        version,
+        contract_address,
+        fee_hash,
+        paymaster_hash,
+        chain_id,
+        nonce,
+        da_modes,
+        constructor_calldata_hash,
+        class_hash,
+        contract_address_salt,
+    );
+    let expected = poseidon_hash_many(&[
+        FieldElement::from_byte_slice_be(DEPLOY_ACCOUNT_PREFIX).unwrap(),
+        version,
+        contract_address,
+        fee_hash,
+        paymaster_hash,
+        chain_id,
+        nonce,
+        da_modes,
+        constructor_calldata_hash,
+        class_hash,
+        contract_address_salt,
+    ]);
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn da_mode_to_felt_packs_nonce_mode_high_and_fee_mode_low() {
+    assert_eq!(da_mode_to_felt(DataAvailabilityMode::L1, DataAvailabilityMode::L1), FieldElement::ZERO);
+    assert_eq!(da_mode_to_felt(DataAvailabilityMode::L1, DataAvailabilityMode::L2), FieldElement::ONE);
+    assert_eq!(
+        da_mode_to_felt(DataAvailabilityMode::L2, DataAvailabilityMode::L1),
+        FieldElement::from(1u64 << 32)
+    );
+    assert_eq!(
+        da_mode_to_felt(DataAvailabilityMode::L2, DataAvailabilityMode::L2),
+        FieldElement::from((1u64 << 32) + 1)
+    );
+}